@@ -2,17 +2,64 @@
 //! The width should be the reciprocal of max_overlap.
 //! Inspired by moinudin's comment on
 //! https://stackoverflow.com/questions/4542892/possible-interview-question-how-to-find-all-overlapping-intervals
+//!
+//! `1/max_overlap` gives each event a width but no horizontal position, so
+//! `layout` computes the actual column-packed geometry.
 
 use std::cmp::Ordering;
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum Cap {
     End,
     Start,
 }
 
+// Reasons an `Interval::new` (or `new_allowing_point`) call can fail, so
+// malformed input is reported to the caller instead of panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntervalError {
+    Reversed,
+    Empty,
+}
+
+// A half-open `[start, end)` interval over any totally-ordered, copyable
+// time type — `i64` epoch seconds and `chrono::NaiveDateTime` both work,
+// unlike the crate's original hardcoded `f32`. Constructing one validates
+// the bounds, so an `Interval` is correct-by-construction: once you have
+// one, downstream code never needs to re-check it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Interval<T: Ord + Copy> {
+    start: T,
+    end: T,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+    // Rejects reversed (`start > end`) and zero-length (`start == end`) bounds.
+    fn new(start: T, end: T) -> Result<Self, IntervalError> {
+        Self::build(start, end, false)
+    }
+
+    // Like `new`, but allows zero-length (point) events through.
+    fn new_allowing_point(start: T, end: T) -> Result<Self, IntervalError> {
+        Self::build(start, end, true)
+    }
+
+    fn build(start: T, end: T, allow_empty: bool) -> Result<Self, IntervalError> {
+        if start > end {
+            return Err(IntervalError::Reversed);
+        }
+        if start == end && !allow_empty {
+            return Err(IntervalError::Empty);
+        }
+        Ok(Interval { start, end })
+    }
+}
+
 fn main() {
-    let meetings = vec![(1., 3.), (4., 6.), (5., 9.), (10., 12.)];
+    let meetings: Vec<Interval<i64>> = vec![(1, 3), (4, 6), (5, 9), (10, 12)]
+        .into_iter()
+        .map(|(start, end)| Interval::new(start, end).expect("valid interval"))
+        .collect();
     let max_overlap = solve_max_overlap(&meetings);
     println!("Checking meetings {:?}...", meetings);
     for i in 0..meetings.len() {
@@ -21,22 +68,74 @@ fn main() {
             meetings[i], max_overlap[i]
         );
     }
+
+    let reminder = Interval::new_allowing_point(9, 9).expect("valid point interval");
+    println!("Reminder {:?} is a zero-length point-in-time event", reminder);
+
+    let calendar = vec![(1., 3.), (4., 6.), (5., 9.), (10., 12.)];
+    let widths = layout(&calendar).expect("valid calendar");
+    println!("Rendering calendar {:?}...", calendar);
+    for i in 0..calendar.len() {
+        println!(
+            "Meeting {:?} has left={} width={}",
+            calendar[i], widths[i].0, widths[i].1
+        );
+    }
+
+    let valued_meetings = vec![(1., 3., 5), (4., 6., 1), (5., 9., 10), (10., 12., 2)];
+    let best_pair = max_two_events(&valued_meetings).expect("valid calendar");
+    println!(
+        "Best combined value of two non-overlapping meetings in {:?} is {}",
+        valued_meetings, best_pair
+    );
+
+    let set_a = IntervalSet::new(vec![(1., 3.), (8., 10.)]).expect("valid calendar");
+    let set_b = IntervalSet::new(vec![(2., 5.), (9., 11.)]).expect("valid calendar");
+    println!(
+        "Union of {:?} and {:?} is {:?}",
+        set_a,
+        set_b,
+        set_a.union(&set_b)
+    );
+    println!(
+        "Intersection of {:?} and {:?} is {:?}",
+        set_a,
+        set_b,
+        set_a.intersection(&set_b)
+    );
+    println!(
+        "Connected components of {:?} are {:?}",
+        set_a,
+        set_a.connected_components()
+    );
+
+    let index = build_index(&calendar).expect("valid calendar");
+    println!(
+        "Meetings in {:?} overlapping [4.5, 8.0) are {:?}",
+        calendar,
+        index.find(4.5, 8.)
+    );
+    println!(
+        "Count of meetings in {:?} overlapping [4.5, 8.0) is {}",
+        calendar,
+        index.count(4.5, 8.)
+    );
 }
 
-fn create_breakpoints(meetings: &[(f32, f32)]) -> Vec<(f32, Cap)> {
+fn create_breakpoints<T: Ord + Copy>(meetings: &[Interval<T>]) -> Vec<(T, Cap)> {
     let mut endpoints = vec![];
     for meeting in meetings {
-        endpoints.push((meeting.0, Cap::Start));
-        endpoints.push((meeting.1, Cap::End));
+        endpoints.push((meeting.start, Cap::Start));
+        endpoints.push((meeting.end, Cap::End));
     }
-    endpoints.sort_by(|a, b| match (a.0).partial_cmp(&b.0).unwrap() {
-        Ordering::Equal => (a.1).partial_cmp(&b.1).unwrap(),
+    endpoints.sort_by(|a, b| match a.0.cmp(&b.0) {
+        Ordering::Equal => a.1.cmp(&b.1),
         other => other,
     });
     endpoints
 }
 
-fn create_stack_count(breakpoints: &[(f32, Cap)]) -> Vec<usize> {
+fn create_stack_count<T>(breakpoints: &[(T, Cap)]) -> Vec<usize> {
     let mut count = vec![];
     let mut curr = 0;
     for breakpoint in breakpoints {
@@ -50,63 +149,330 @@ fn create_stack_count(breakpoints: &[(f32, Cap)]) -> Vec<usize> {
     count
 }
 
-fn slice_index(
-    meeting: &(f32, f32),
-    breakpoints: &[(f32, Cap)],
-    num_meetings: usize,
-) -> (usize, usize) {
-    let meeting_start = meeting.0;
-    let meeting_end = meeting.1;
-    let mut start_index = 0;
-    while breakpoints[start_index].0 < meeting_start
-        || (breakpoints[start_index].0 == meeting_start && breakpoints[start_index].1 == Cap::End)
-    {
-        start_index += 1;
+// Returns floor(log2(n)) for n >= 1, used to size sparse table rows.
+fn log2_floor(n: usize) -> usize {
+    (usize::BITS - 1 - n.leading_zeros()) as usize
+}
+
+// Builds a sparse table for O(1) range-maximum queries over `values`.
+// `table[k][i] = max(values[i .. i + 2^k])`.
+fn build_sparse_table(values: &[usize]) -> Vec<Vec<usize>> {
+    let n = values.len();
+    if n == 0 {
+        return vec![];
     }
-    let mut end_index = start_index;
-    while end_index < 2 * num_meetings && breakpoints[end_index].0 < meeting_end {
-        end_index += 1;
+    let levels = log2_floor(n) + 1;
+    let mut table = vec![values.to_vec()];
+    for k in 1..levels {
+        let prev = &table[k - 1];
+        let span = 1 << (k - 1);
+        let mut row = vec![0; n - (1 << k) + 1];
+        for (i, slot) in row.iter_mut().enumerate() {
+            *slot = std::cmp::max(prev[i], prev[i + span]);
+        }
+        table.push(row);
     }
+    table
+}
+
+// Queries the maximum of `values[l..r]` using a sparse table built by `build_sparse_table`.
+fn range_max(table: &[Vec<usize>], l: usize, r: usize) -> usize {
+    let k = log2_floor(r - l);
+    let span = 1 << k;
+    std::cmp::max(table[k][l], table[k][r - span])
+}
+
+// Binary-searches the sorted `breakpoints` for the slice a meeting's max
+// overlap must be taken over, rather than scanning from the start on every
+// call — the scan alone was O(n) per meeting, which made the overall
+// algorithm O(n^2) even with the sparse table in place.
+fn slice_index<T: Ord + Copy>(meeting: &Interval<T>, breakpoints: &[(T, Cap)]) -> (usize, usize) {
+    let start_index = breakpoints
+        .partition_point(|bp| bp.0 < meeting.start || (bp.0 == meeting.start && bp.1 == Cap::End));
+    let end_index = breakpoints.partition_point(|bp| bp.0 < meeting.end);
     (start_index, end_index + 1)
 }
 
-// This function has O(n^2): O(n) for looping through meetings and O(n) for finding max_overlap of each meeting.
-fn solve_max_overlap(meetings: &[(f32, f32)]) -> Vec<usize> {
-    is_valid(meetings);
-    let breakpoints = create_breakpoints(&meetings);
+// O(n log n): breakpoints + stack_count are built once, and each meeting's
+// max overlap is answered with an O(1) range-max query against a sparse
+// table built once over stack_count.
+fn solve_max_overlap<T: Ord + Copy>(meetings: &[Interval<T>]) -> Vec<usize> {
+    let breakpoints = create_breakpoints(meetings);
     let stack_count = create_stack_count(&breakpoints);
+    let sparse = build_sparse_table(&stack_count);
     let mut result = vec![];
     for meeting in meetings {
-        let (start_index, end_index) = slice_index(&meeting, &breakpoints, meetings.len());
-        let mut max_overlap = stack_count[start_index];
-        for index in start_index..end_index {
-            max_overlap = std::cmp::max(stack_count[index], max_overlap);
-        }
-        result.push(max_overlap);
+        let (start_index, end_index) = slice_index(meeting, &breakpoints);
+        result.push(range_max(&sparse, start_index, end_index));
     }
     result
 }
 
-fn is_valid(meetings: &[(f32, f32)]) {
+// Column-packs meetings for rendering: each event gets a `(left, width)` pair
+// in `[0, 1]`. Events are swept in start order and grouped into clusters for
+// as long as the next event starts before the latest end seen so far; within
+// a cluster each event takes the lowest-numbered column whose last-placed
+// event has already ended, so events that only share a common neighbour can
+// share a column. A cluster's column count gives the width (`1/columns`)
+// shared by every event in it; this is never wider than an event's own
+// `1/max_overlap`.
+fn layout(meetings: &[(f32, f32)]) -> Result<Vec<(f32, f32)>, IntervalError> {
+    is_valid(meetings)?;
+    let mut order: Vec<usize> = (0..meetings.len()).collect();
+    order.sort_by(|&a, &b| match meetings[a].0.partial_cmp(&meetings[b].0).unwrap() {
+        Ordering::Equal => meetings[a].1.partial_cmp(&meetings[b].1).unwrap(),
+        other => other,
+    });
+
+    let mut result = vec![(0., 0.); meetings.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut column_ends: Vec<f32> = vec![meetings[order[i]].1];
+        let mut cluster = vec![(order[i], 0usize)];
+        let mut cluster_end = meetings[order[i]].1;
+        i += 1;
+
+        while i < order.len() && meetings[order[i]].0 < cluster_end {
+            let idx = order[i];
+            let (start, end) = meetings[idx];
+            let column = column_ends.iter().position(|&col_end| col_end <= start);
+            match column {
+                Some(column) => column_ends[column] = end,
+                None => column_ends.push(end),
+            }
+            cluster.push((idx, column.unwrap_or(column_ends.len() - 1)));
+            cluster_end = f32::max(cluster_end, end);
+            i += 1;
+        }
+
+        let num_columns = column_ends.len() as f32;
+        for (idx, column) in cluster {
+            result[idx] = (column as f32 / num_columns, 1. / num_columns);
+        }
+    }
+    Ok(result)
+}
+
+// Returns the largest combined value of two non-overlapping events from
+// `events`, or the value of a single event if pairing never pays off. Events
+// are sorted by start, and a suffix-maximum array over their values lets each
+// event look up, with one binary search, the best value among events that
+// start at or after its own end (the half-open convention means a touching
+// pair like `(1,3)` and `(3,5)` counts as non-overlapping).
+fn max_two_events(events: &[(f32, f32, i64)]) -> Result<i64, IntervalError> {
+    if events.is_empty() {
+        return Ok(0);
+    }
+    let meetings: Vec<(f32, f32)> = events.iter().map(|&(start, end, _)| (start, end)).collect();
+    is_valid(&meetings)?;
+
+    let mut order: Vec<usize> = (0..events.len()).collect();
+    order.sort_by(|&a, &b| events[a].0.partial_cmp(&events[b].0).unwrap());
+    let starts: Vec<f32> = order.iter().map(|&i| events[i].0).collect();
+
+    let mut suffix_max = vec![0; order.len() + 1];
+    for i in (0..order.len()).rev() {
+        suffix_max[i] = std::cmp::max(suffix_max[i + 1], events[order[i]].2);
+    }
+
+    let mut best = i64::MIN;
+    for &i in &order {
+        let (_, end, value) = events[i];
+        let idx = starts.partition_point(|&start| start < end);
+        best = std::cmp::max(best, value + suffix_max[idx]);
+    }
+    Ok(best)
+}
+
+// Validates `(start, end)` pairs the same way `Interval::build` does, for
+// the functions in this crate that predate `Interval<T>` and still work
+// directly on `f32` pairs (`f32` has no `Ord` impl, so it can't use
+// `Interval<T: Ord + Copy>` itself). Reports malformed input instead of
+// panicking on it.
+fn is_valid(meetings: &[(f32, f32)]) -> Result<(), IntervalError> {
     for meeting in meetings {
-        assert!(meeting.0 < meeting.1);
+        if meeting.0 > meeting.1 {
+            return Err(IntervalError::Reversed);
+        }
+        if meeting.0 == meeting.1 {
+            return Err(IntervalError::Empty);
+        }
+    }
+    Ok(())
+}
+
+// A reusable set of intervals, always kept sorted by start and (for equal
+// starts) descending end, so every operation below can assume that order.
+// Indices handed out by `connected_components` refer to this internal order,
+// not whatever order the caller originally passed in.
+#[derive(Debug, Clone, PartialEq)]
+struct IntervalSet {
+    intervals: Vec<(f32, f32)>,
+}
+
+impl IntervalSet {
+    fn new(mut intervals: Vec<(f32, f32)>) -> Result<Self, IntervalError> {
+        is_valid(&intervals)?;
+        intervals.sort_by(|a, b| match a.0.partial_cmp(&b.0).unwrap() {
+            Ordering::Equal => b.1.partial_cmp(&a.1).unwrap(),
+            other => other,
+        });
+        Ok(IntervalSet { intervals })
+    }
+
+    // Collapses touching or overlapping intervals into maximal runs via a
+    // single left-to-right pass, extending the current run's end whenever
+    // the next interval starts at or before it, and flushing otherwise.
+    fn merge_overlapping(&self) -> IntervalSet {
+        let mut merged = vec![];
+        let mut iter = self.intervals.iter();
+        if let Some(&first) = iter.next() {
+            let mut current = first;
+            for &(start, end) in iter {
+                if start <= current.1 {
+                    current.1 = f32::max(current.1, end);
+                } else {
+                    merged.push(current);
+                    current = (start, end);
+                }
+            }
+            merged.push(current);
+        }
+        IntervalSet { intervals: merged }
+    }
+
+    fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut combined = self.intervals.clone();
+        combined.extend(other.intervals.iter().copied());
+        // `self` and `other` are already-validated `IntervalSet`s, so their
+        // combined intervals can't fail validation again.
+        IntervalSet::new(combined)
+            .expect("intervals from existing IntervalSets are already valid")
+            .merge_overlapping()
+    }
+
+    // Pairwise overlaps between the two (already merged) sets, walked with
+    // the standard two-pointer sweep over sorted intervals.
+    fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let a = self.merge_overlapping();
+        let b = other.merge_overlapping();
+        let mut overlaps = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < a.intervals.len() && j < b.intervals.len() {
+            let (a_start, a_end) = a.intervals[i];
+            let (b_start, b_end) = b.intervals[j];
+            let start = f32::max(a_start, b_start);
+            let end = f32::min(a_end, b_end);
+            if start < end {
+                overlaps.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals: overlaps }
+    }
+
+    // Groups indices into `self.intervals` that transitively overlap,
+    // i.e. the same clusters `layout` sweeps over. Unlike
+    // `merge_overlapping`, touching intervals are *not* connected here —
+    // this matches the half-open convention used everywhere else in the
+    // crate (`layout`, `max_two_events`, `EventIndex`), where `(1,3)` and
+    // `(3,5)` are independent.
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut components = vec![];
+        let mut current: Vec<usize> = vec![];
+        let mut current_end = f32::MIN;
+        for (index, &(start, end)) in self.intervals.iter().enumerate() {
+            if !current.is_empty() && start >= current_end {
+                components.push(std::mem::take(&mut current));
+            }
+            current.push(index);
+            current_end = f32::max(current_end, end);
+        }
+        if !current.is_empty() {
+            components.push(current);
+        }
+        components
+    }
+}
+
+// A lapper-style overlap index: intervals sorted by start plus the longest
+// interval length, `max_len`. A query for `[query_start, query_end)` only
+// ever needs to look at intervals starting no earlier than
+// `query_start - max_len`, since anything earlier must already have ended
+// by `query_start`. This answers arbitrary (non-breakpoint) time windows in
+// near O(log n + k), unlike the breakpoint-based `solve_max_overlap`.
+#[derive(Debug, PartialEq)]
+struct EventIndex {
+    sorted: Vec<(f32, f32, usize)>,
+    max_len: f32,
+}
+
+fn build_index(meetings: &[(f32, f32)]) -> Result<EventIndex, IntervalError> {
+    is_valid(meetings)?;
+    let mut sorted: Vec<(f32, f32, usize)> = meetings
+        .iter()
+        .enumerate()
+        .map(|(index, &(start, end))| (start, end, index))
+        .collect();
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let max_len = sorted
+        .iter()
+        .map(|&(start, end, _)| end - start)
+        .fold(0., f32::max);
+    Ok(EventIndex { sorted, max_len })
+}
+
+impl EventIndex {
+    // Returns the indices (into the original `meetings` passed to
+    // `build_index`) of every meeting overlapping `[query_start, query_end)`.
+    fn find(&self, query_start: f32, query_end: f32) -> Vec<usize> {
+        let threshold = query_start - self.max_len;
+        let from = self.sorted.partition_point(|&(start, _, _)| start < threshold);
+        let mut result = vec![];
+        for &(start, end, index) in &self.sorted[from..] {
+            if start >= query_end {
+                break;
+            }
+            if end > query_start {
+                result.push(index);
+            }
+        }
+        result
+    }
+
+    fn count(&self, query_start: f32, query_end: f32) -> usize {
+        self.find(query_start, query_end).len()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    // Builds a validated `Interval<i64>` for test fixtures.
+    fn iv(start: i64, end: i64) -> Interval<i64> {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn intervals(pairs: &[(i64, i64)]) -> Vec<Interval<i64>> {
+        pairs.iter().map(|&(start, end)| iv(start, end)).collect()
+    }
+
     #[test]
     fn test_breakpoints() {
-        let meetings = [(1.0, 2.0), (2.0, 3.0)];
+        let meetings = intervals(&[(1, 2), (2, 3)]);
         let breakpoints = create_breakpoints(&meetings);
         assert_eq!(
             breakpoints,
             vec![
-                (1.0, Cap::Start),
-                (2.0, Cap::End),
-                (2.0, Cap::Start),
-                (3.0, Cap::End)
+                (1, Cap::Start),
+                (2, Cap::End),
+                (2, Cap::Start),
+                (3, Cap::End)
             ]
         );
     }
@@ -114,30 +480,40 @@ mod tests {
     #[test]
     fn test_stack_count() {
         let breakpoints = vec![
-            (1.0, Cap::Start),
-            (2.0, Cap::End),
-            (2.0, Cap::Start),
-            (3.0, Cap::End),
+            (1, Cap::Start),
+            (2, Cap::End),
+            (2, Cap::Start),
+            (3, Cap::End),
         ];
         let count = create_stack_count(&breakpoints);
         assert_eq!(count, vec![1, 0, 1, 0]);
     }
 
+    #[test]
+    fn test_sparse_table_range_max() {
+        let values = vec![1, 0, 1, 2, 1, 0, 1, 0];
+        let table = build_sparse_table(&values);
+        assert_eq!(range_max(&table, 0, 8), 2);
+        assert_eq!(range_max(&table, 0, 1), 1);
+        assert_eq!(range_max(&table, 4, 8), 1);
+        assert_eq!(range_max(&table, 2, 5), 2);
+    }
+
     #[test]
     fn test_max_overlap_standard() {
-        let meetings = vec![(1., 3.), (4., 6.), (5., 9.), (10., 12.)];
+        let meetings = intervals(&[(1, 3), (4, 6), (5, 9), (10, 12)]);
         let breakpoints = create_breakpoints(&meetings);
         assert_eq!(
             breakpoints,
             vec![
-                (1., Cap::Start),
-                (3., Cap::End),
-                (4., Cap::Start),
-                (5., Cap::Start),
-                (6., Cap::End),
-                (9., Cap::End),
-                (10., Cap::Start),
-                (12., Cap::End)
+                (1, Cap::Start),
+                (3, Cap::End),
+                (4, Cap::Start),
+                (5, Cap::Start),
+                (6, Cap::End),
+                (9, Cap::End),
+                (10, Cap::Start),
+                (12, Cap::End)
             ]
         );
         let stack_count = create_stack_count(&breakpoints);
@@ -148,38 +524,38 @@ mod tests {
 
     #[test]
     fn test_1313() {
-        let meetings = vec![(1., 3.), (1., 3.)];
+        let meetings = intervals(&[(1, 3), (1, 3)]);
         let max_overlap = solve_max_overlap(&meetings);
         assert_eq!(max_overlap, vec![2, 2]);
     }
 
     #[test]
     fn test_1335() {
-        let meetings = vec![(1., 3.), (3., 5.)];
+        let meetings = intervals(&[(1, 3), (3, 5)]);
         let max_overlap = solve_max_overlap(&meetings);
         assert_eq!(max_overlap, vec![1, 1]);
     }
 
     #[test]
     fn test_123536() {
-        let meetings = vec![(1., 2.), (3., 5.), (3., 6.)];
+        let meetings = intervals(&[(1, 2), (3, 5), (3, 6)]);
         let max_overlap = solve_max_overlap(&meetings);
         assert_eq!(max_overlap, vec![1, 2, 2]);
     }
 
     #[test]
     fn test_133536() {
-        let meetings = vec![(1., 3.), (3., 5.), (3., 6.)];
+        let meetings = intervals(&[(1, 3), (3, 5), (3, 6)]);
         let breakpoints = create_breakpoints(&meetings);
         assert_eq!(
             breakpoints,
             vec![
-                (1., Cap::Start),
-                (3., Cap::End),
-                (3., Cap::Start),
-                (3., Cap::Start),
-                (5., Cap::End),
-                (6., Cap::End)
+                (1, Cap::Start),
+                (3, Cap::End),
+                (3, Cap::Start),
+                (3, Cap::Start),
+                (5, Cap::End),
+                (6, Cap::End)
             ]
         );
         let max_overlap = solve_max_overlap(&meetings);
@@ -188,15 +564,15 @@ mod tests {
 
     #[test]
     fn test_3513() {
-        let meetings = vec![(3., 5.), (1., 3.)];
+        let meetings = intervals(&[(3, 5), (1, 3)]);
         let breakpoints = create_breakpoints(&meetings);
         assert_eq!(
             breakpoints,
             vec![
-                (1., Cap::Start),
-                (3., Cap::End),
-                (3., Cap::Start),
-                (5., Cap::End)
+                (1, Cap::Start),
+                (3, Cap::End),
+                (3, Cap::Start),
+                (5, Cap::End)
             ]
         );
         let max_overlap = solve_max_overlap(&meetings);
@@ -205,36 +581,196 @@ mod tests {
 
     #[test]
     fn test_1534() {
-        let meetings = vec![(1., 5.), (3., 4.)];
+        let meetings = intervals(&[(1, 5), (3, 4)]);
         let max_overlap = solve_max_overlap(&meetings);
         assert_eq!(max_overlap, vec![2, 2]);
     }
 
     #[test]
     fn test_1325() {
-        let meetings = vec![(1., 3.), (2., 5.)];
+        let meetings = intervals(&[(1, 3), (2, 5)]);
         let max_overlap = solve_max_overlap(&meetings);
         assert_eq!(max_overlap, vec![2, 2]);
     }
 
     #[test]
     fn test_19121367() {
-        let meetings = vec![(1., 9.), (1., 2.), (1., 3.), (6., 7.)];
+        let meetings = intervals(&[(1, 9), (1, 2), (1, 3), (6, 7)]);
         let max_overlap = solve_max_overlap(&meetings);
         assert_eq!(max_overlap, vec![3, 3, 3, 2]);
     }
 
     #[test]
-    #[should_panic]
+    fn test_layout_standard() {
+        let meetings = vec![(1., 3.), (4., 6.), (5., 9.), (10., 12.)];
+        let layout = layout(&meetings).unwrap();
+        assert_eq!(
+            layout,
+            vec![(0., 1.), (0., 0.5), (0.5, 0.5), (0., 1.)]
+        );
+    }
+
+    #[test]
+    fn test_layout_shared_column_across_cluster() {
+        // (1,3) and (6,8) don't overlap each other, only the long (2,7)
+        // spanning both, so they should be able to share a column even
+        // though max_overlap at the shared midpoint is 2.
+        let meetings = vec![(1., 3.), (2., 7.), (6., 8.)];
+        let layout = layout(&meetings).unwrap();
+        assert_eq!(layout, vec![(0., 0.5), (0.5, 0.5), (0., 0.5)]);
+    }
+
+    #[test]
+    fn test_layout_touching_events_share_column() {
+        let meetings = vec![(1., 3.), (3., 5.)];
+        let layout = layout(&meetings).unwrap();
+        assert_eq!(layout, vec![(0., 1.), (0., 1.)]);
+    }
+
+    #[test]
+    fn test_layout_rejects_invalid_interval() {
+        assert_eq!(layout(&[(3., 1.)]), Err(IntervalError::Reversed));
+    }
+
+    #[test]
+    fn test_max_two_events_standard() {
+        // (1,3,5) and (5,9,10) don't overlap and beat any other pairing.
+        let events = vec![(1., 3., 5), (4., 6., 1), (5., 9., 10), (10., 12., 2)];
+        assert_eq!(max_two_events(&events), Ok(15));
+    }
+
+    #[test]
+    fn test_max_two_events_touching_counts_as_non_overlapping() {
+        let events = vec![(1., 3., 4), (3., 5., 6)];
+        assert_eq!(max_two_events(&events), Ok(10));
+    }
+
+    #[test]
+    fn test_max_two_events_single_event_is_best() {
+        // Every pair overlaps, so the best single event wins alone.
+        let events = vec![(1., 5., 3), (2., 6., 7)];
+        assert_eq!(max_two_events(&events), Ok(7));
+    }
+
+    #[test]
+    fn test_max_two_events_empty() {
+        assert_eq!(max_two_events(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_max_two_events_rejects_invalid_interval() {
+        assert_eq!(
+            max_two_events(&[(1., 1., 5)]),
+            Err(IntervalError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_interval_set_new_sorts_by_start_then_descending_end() {
+        let set = IntervalSet::new(vec![(3., 5.), (1., 4.), (1., 2.)]).unwrap();
+        assert_eq!(set.intervals, vec![(1., 4.), (1., 2.), (3., 5.)]);
+    }
+
+    #[test]
+    fn test_interval_set_new_rejects_invalid_interval() {
+        assert_eq!(
+            IntervalSet::new(vec![(3., 1.)]),
+            Err(IntervalError::Reversed)
+        );
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        let set = IntervalSet::new(vec![(1., 3.), (2., 6.), (8., 10.), (10., 12.)]).unwrap();
+        let merged = set.merge_overlapping();
+        assert_eq!(merged.intervals, vec![(1., 6.), (8., 12.)]);
+    }
+
+    #[test]
+    fn test_union() {
+        let a = IntervalSet::new(vec![(1., 3.), (8., 10.)]).unwrap();
+        let b = IntervalSet::new(vec![(2., 5.), (9., 11.)]).unwrap();
+        let union = a.union(&b);
+        assert_eq!(union.intervals, vec![(1., 5.), (8., 11.)]);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = IntervalSet::new(vec![(1., 5.), (8., 12.)]).unwrap();
+        let b = IntervalSet::new(vec![(3., 9.), (11., 14.)]).unwrap();
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.intervals, vec![(3., 5.), (8., 9.), (11., 12.)]);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let set = IntervalSet::new(vec![(1., 3.), (2., 7.), (6., 8.), (10., 12.)]).unwrap();
+        let components = set.connected_components();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_connected_components_touching_intervals_are_separate() {
+        // Unlike merge_overlapping, touching intervals don't join a
+        // component here, matching layout's clustering of the same input.
+        let set = IntervalSet::new(vec![(1., 3.), (3., 5.)]).unwrap();
+        let components = set.connected_components();
+        assert_eq!(components, vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn test_event_index_find_at_non_boundary_time() {
+        let meetings = vec![(1., 3.), (4., 6.), (5., 9.), (10., 12.)];
+        let index = build_index(&meetings).unwrap();
+        let mut found = index.find(4.5, 4.6);
+        found.sort();
+        assert_eq!(found, vec![1]);
+    }
+
+    #[test]
+    fn test_event_index_find_spanning_window() {
+        let meetings = vec![(1., 3.), (4., 6.), (5., 9.), (10., 12.)];
+        let index = build_index(&meetings).unwrap();
+        let mut found = index.find(4.5, 8.);
+        found.sort();
+        assert_eq!(found, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_event_index_touching_query_excludes_event() {
+        let meetings = vec![(1., 3.), (3., 5.)];
+        let index = build_index(&meetings).unwrap();
+        assert_eq!(index.find(3., 5.), vec![1]);
+    }
+
+    #[test]
+    fn test_event_index_count() {
+        let meetings = vec![(1., 9.), (1., 2.), (1., 3.), (6., 7.)];
+        let index = build_index(&meetings).unwrap();
+        assert_eq!(index.count(1., 2.), 3);
+        assert_eq!(index.count(6.5, 6.6), 2);
+    }
+
+    #[test]
+    fn test_build_index_rejects_invalid_interval() {
+        assert_eq!(build_index(&[(1., 1.)]), Err(IntervalError::Empty));
+    }
+
+    #[test]
     fn test_end_time_before_start_time() {
-        let meetings = vec![(3., 1.), (5., 6.)];
-        solve_max_overlap(&meetings);
+        assert_eq!(Interval::new(3, 1), Err(IntervalError::Reversed));
     }
 
     #[test]
-    #[should_panic]
     fn test_end_time_equal_start_time() {
-        let meetings = vec![(1., 1.)];
-        solve_max_overlap(&meetings);
+        assert_eq!(Interval::new(1, 1), Err(IntervalError::Empty));
+    }
+
+    #[test]
+    fn test_end_time_equal_start_time_allowed_as_point() {
+        assert_eq!(
+            Interval::new_allowing_point(1, 1),
+            Ok(Interval { start: 1, end: 1 })
+        );
     }
 }